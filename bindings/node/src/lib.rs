@@ -0,0 +1,47 @@
+//! Node.js bindings for the `kit` greeter, built as a `cdylib` with
+//! [neon](https://neon-bindings.com/). This crate is optional and lives
+//! alongside the main library rather than inside it, so plain Rust
+//! consumers never pull in the neon/N-API dependency chain.
+
+use kit::{greet, Greet, Greeter};
+use neon::prelude::*;
+
+/// `greet(name: string) -> string`
+fn js_greet(mut cx: FunctionContext) -> JsResult<JsString> {
+    let name = cx.argument::<JsString>(0)?.value(&mut cx);
+    Ok(cx.string(greet(&name)))
+}
+
+/// `greeting(greetingWord: string, thing: string) -> string`
+fn js_greeting(mut cx: FunctionContext) -> JsResult<JsString> {
+    let greeting_word = cx.argument::<JsString>(0)?.value(&mut cx);
+    let thing = cx.argument::<JsString>(1)?.value(&mut cx);
+    let greeter = Greeter::new(&greeting_word);
+    Ok(cx.string(greeter.greeting(&thing)))
+}
+
+/// `greetingFromFile(path: string, thing: string) -> string`, throwing a JS
+/// exception if the greeting file is missing or empty.
+fn js_greeting_from_file(mut cx: FunctionContext) -> JsResult<JsString> {
+    let path = cx.argument::<JsString>(0)?.value(&mut cx);
+    let thing = cx.argument::<JsString>(1)?.value(&mut cx);
+    let greeter = Greeter::from_file(path).or_else(|err| cx.throw_error(err.to_string()))?;
+    Ok(cx.string(greeter.greeting(&thing)))
+}
+
+/// `greetingForLocale(locale: string, thing: string) -> string`
+fn js_greeting_for_locale(mut cx: FunctionContext) -> JsResult<JsString> {
+    let locale = cx.argument::<JsString>(0)?.value(&mut cx);
+    let thing = cx.argument::<JsString>(1)?.value(&mut cx);
+    let greeter = Greeter::with_locale(&locale);
+    Ok(cx.string(greeter.greeting(&thing)))
+}
+
+#[neon::main]
+fn main(mut cx: ModuleContext) -> NeonResult<()> {
+    cx.export_function("greet", js_greet)?;
+    cx.export_function("greeting", js_greeting)?;
+    cx.export_function("greetingFromFile", js_greeting_from_file)?;
+    cx.export_function("greetingForLocale", js_greeting_for_locale)?;
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::{OnceLock, RwLock};
+
+pub fn greet(name: &str) -> String {
+    format!("Hello {}", name)
+}
+
+pub struct Greeter<'a> {
+    pub name: &'a str,
+    greeting: String,
+}
+
+impl<'a> Greeter<'a> {
+    pub fn new(greeting: &str) -> Greeter<'a> {
+        Greeter {
+            name: "",
+            greeting: greeting.to_string(),
+        }
+    }
+
+    /// Thin wrapper over the old name-based API: formats `self.name` using
+    /// this greeter's configured greeting word. It is not a drop-in
+    /// replacement for the original `Greeter { name }.greet()` call: the
+    /// struct can no longer be built with a literal outside this crate, and
+    /// the method is named `greet_name` rather than `greet`.
+    pub fn greet_name(&self) -> String {
+        self.greeting(self.name)
+    }
+
+    /// Reads the greeting word from `path`, trimming all trailing `\r` and
+    /// `\n` characters.
+    /// Errors if the file can't be read, and also if it's empty, so a
+    /// blank greeting file fails loudly instead of silently greeting with
+    /// an empty prefix.
+    pub fn from_file(path: impl AsRef<Path>) -> io::Result<Greeter<'a>> {
+        let contents = fs::read_to_string(path)?;
+        let greeting = contents.trim_end_matches(['\r', '\n']).to_string();
+        if greeting.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "greeting file is empty",
+            ));
+        }
+        Ok(Greeter {
+            name: "",
+            greeting,
+        })
+    }
+
+    /// Builds a greeter using the greeting word registered for `locale`,
+    /// falling back to English (`"Hello"`) for unknown locales so an
+    /// unrecognized code degrades gracefully instead of erroring.
+    pub fn with_locale(locale: &str) -> Greeter<'a> {
+        let greeting = locale_table()
+            .read()
+            .unwrap()
+            .get(locale)
+            .cloned()
+            .unwrap_or_else(|| "Hello".to_string());
+        Greeter {
+            name: "",
+            greeting,
+        }
+    }
+}
+
+fn locale_table() -> &'static RwLock<HashMap<String, String>> {
+    static TABLE: OnceLock<RwLock<HashMap<String, String>>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        RwLock::new(HashMap::from([
+            ("en".to_string(), "Hello".to_string()),
+            ("fr".to_string(), "Bonjour".to_string()),
+            ("es".to_string(), "Hola".to_string()),
+            ("ja".to_string(), "こんにちは".to_string()),
+        ]))
+    })
+}
+
+/// Registers (or overrides) the greeting word used for `code` by
+/// [`Greeter::with_locale`], so applications can extend the built-in
+/// table at runtime.
+pub fn register_locale(code: &str, greeting: &str) {
+    locale_table()
+        .write()
+        .unwrap()
+        .insert(code.to_string(), greeting.to_string());
+}
+
+/// Extension point for types that can produce a greeting, so callers can
+/// write generic code over `impl Greet` instead of the concrete `Greeter`.
+pub trait Greet {
+    fn greeting(&self, thing: &str) -> String;
+
+    /// Default implementation just prints `greeting`; override for custom
+    /// output (e.g. a GUI toast or a logger) without losing the formatting.
+    fn greet(&self, thing: &str) {
+        println!("{}", self.greeting(thing));
+    }
+}
+
+impl<'a> Greet for Greeter<'a> {
+    fn greeting(&self, thing: &str) -> String {
+        format!("{} {}", self.greeting, thing)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn greeting_file(contents: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let path = std::env::temp_dir().join(format!(
+            "kit-greeting-test-{}-{}.txt",
+            std::process::id(),
+            id
+        ));
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn from_file_reads_and_trims_the_greeting() {
+        let path = greeting_file("Bonjour\n");
+        let greeter = Greeter::from_file(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(greeter.greeting("World"), "Bonjour World");
+    }
+
+    #[test]
+    fn from_file_errors_on_missing_path() {
+        let err = match Greeter::from_file("/no/such/greeting.txt") {
+            Err(err) => err,
+            Ok(_) => panic!("expected an error for a missing path"),
+        };
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn from_file_errors_on_empty_file() {
+        let path = greeting_file("");
+        let err = match Greeter::from_file(&path) {
+            Err(err) => err,
+            Ok(_) => panic!("expected an error for an empty file"),
+        };
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn with_locale_uses_the_registered_word() {
+        let greeter = Greeter::with_locale("fr");
+        assert_eq!(greeter.greeting("World"), "Bonjour World");
+    }
+
+    #[test]
+    fn with_locale_falls_back_to_english_for_unknown_codes() {
+        let greeter = Greeter::with_locale("xx-unknown");
+        assert_eq!(greeter.greeting("World"), "Hello World");
+    }
+
+    #[test]
+    fn register_locale_then_with_locale_round_trips() {
+        register_locale("pirate", "Ahoy");
+        let greeter = Greeter::with_locale("pirate");
+        assert_eq!(greeter.greeting("World"), "Ahoy World");
+    }
+}